@@ -0,0 +1,146 @@
+// src/bitwarden.rs
+/*
+ * Interop with Bitwarden's unencrypted JSON export format
+ */
+
+use serde::{Deserialize, Serialize};
+
+use crate::vault::{Entry, Vault};
+
+/// Bitwarden's `type` field for a login item.
+const ITEM_TYPE_LOGIN: u32 = 1;
+
+/// Top-level shape of a Bitwarden unencrypted JSON export.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BitwardenExport {
+    #[serde(default)]
+    pub folders: Vec<BitwardenFolder>,
+    pub items: Vec<BitwardenItem>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BitwardenFolder {
+    pub id: String,
+    pub name: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BitwardenItem {
+    #[serde(rename = "type")]
+    pub item_type: u32,
+    pub name: String,
+    #[serde(default)]
+    pub notes: String,
+    #[serde(default)]
+    pub login: Option<BitwardenLogin>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BitwardenLogin {
+    #[serde(default)]
+    pub username: Option<String>,
+    #[serde(default)]
+    pub password: Option<String>,
+    #[serde(default)]
+    pub uris: Vec<BitwardenUri>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BitwardenUri {
+    pub uri: String,
+}
+
+/// Converts a [`Vault`] into Bitwarden's unencrypted JSON export shape.
+pub fn to_bitwarden(vault: &Vault) -> BitwardenExport {
+    let items = vault
+        .entries
+        .iter()
+        .map(|entry| BitwardenItem {
+            item_type: ITEM_TYPE_LOGIN,
+            name: entry.name.clone(),
+            notes: entry.notes.clone(),
+            login: Some(BitwardenLogin {
+                username: Some(entry.username.clone()),
+                password: Some(entry.password.clone()),
+                uris: Vec::new(),
+            }),
+        })
+        .collect();
+
+    BitwardenExport { folders: Vec::new(), items }
+}
+
+/// Converts a Bitwarden export into a [`Vault`], skipping folders and any
+/// item that isn't a login. Returns warnings describing what was skipped.
+pub fn from_bitwarden(export: BitwardenExport) -> (Vault, Vec<String>) {
+    let mut vault = Vault::new();
+    let mut warnings = Vec::new();
+
+    for item in export.items {
+        if item.item_type != ITEM_TYPE_LOGIN {
+            warnings.push(format!("skipping unsupported item \"{}\" (type {})", item.name, item.item_type));
+            continue;
+        }
+
+        let Some(login) = item.login else {
+            warnings.push(format!("skipping login item \"{}\" with no login data", item.name));
+            continue;
+        };
+
+        let entry = Entry {
+            name: item.name.clone(),
+            username: login.username.unwrap_or_default(),
+            password: login.password.unwrap_or_default(),
+            notes: item.notes,
+        };
+
+        if let Err(e) = vault.add(entry) {
+            warnings.push(format!("skipping \"{}\": {e}", item.name));
+        }
+    }
+
+    (vault, warnings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_vault() -> Vault {
+        let mut vault = Vault::new();
+        vault
+            .add(Entry {
+                name: "github".to_string(),
+                username: "alice".to_string(),
+                password: "hunter2".to_string(),
+                notes: "work account".to_string(),
+            })
+            .unwrap();
+        vault
+    }
+
+    #[test]
+    fn export_then_import_round_trips() {
+        let export = to_bitwarden(&sample_vault());
+        let (imported, warnings) = from_bitwarden(export);
+        assert!(warnings.is_empty());
+        assert_eq!(imported.entries.len(), 1);
+        assert_eq!(imported.entries[0].username, "alice");
+    }
+
+    #[test]
+    fn import_skips_non_login_items_with_warning() {
+        let export = BitwardenExport {
+            folders: vec![BitwardenFolder { id: "1".to_string(), name: "Personal".to_string() }],
+            items: vec![BitwardenItem {
+                item_type: 2, // secure note
+                name: "note".to_string(),
+                notes: String::new(),
+                login: None,
+            }],
+        };
+        let (imported, warnings) = from_bitwarden(export);
+        assert!(imported.entries.is_empty());
+        assert_eq!(warnings.len(), 1);
+    }
+}
@@ -0,0 +1,68 @@
+// src/cipher.rs
+/*
+ * Stream cipher primitives used by the `encrypt`/`decrypt` subcommands
+ */
+
+use crate::error::{Error, Result};
+
+/// Decodes a hex-encoded key into raw bytes.
+///
+/// Rejects an empty key, keys with an odd number of hex digits, and any
+/// non-hex character. An empty key would otherwise pass hex validation
+/// vacuously and make [`apply_keystream`] a no-op.
+pub fn decode_hex_key(key: &str) -> Result<Vec<u8>> {
+    if key.is_empty() || !key.len().is_multiple_of(2) || !key.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(Error::InvalidHexKey(key.to_string()));
+    }
+
+    (0..key.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&key[i..i + 2], 16).map_err(|_| Error::InvalidHexKey(key.to_string())))
+        .collect()
+}
+
+/// Applies a repeating-key XOR keystream to `data` in place.
+///
+/// This is the stream cipher used by the `encrypt`/`decrypt` subcommands;
+/// applying it twice with the same key recovers the original bytes.
+pub fn apply_keystream(data: &mut [u8], key: &[u8]) {
+    for (byte, k) in data.iter_mut().zip(key.iter().cycle()) {
+        *byte ^= k;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_hex_key_accepts_valid_hex() {
+        assert_eq!(decode_hex_key("00ff").unwrap(), vec![0x00, 0xff]);
+    }
+
+    #[test]
+    fn decode_hex_key_rejects_odd_length() {
+        assert!(decode_hex_key("abc").is_err());
+    }
+
+    #[test]
+    fn decode_hex_key_rejects_non_hex() {
+        assert!(decode_hex_key("zz").is_err());
+    }
+
+    #[test]
+    fn decode_hex_key_rejects_empty_key() {
+        assert!(decode_hex_key("").is_err());
+    }
+
+    #[test]
+    fn apply_keystream_round_trips() {
+        let key = [0x2a, 0x13];
+        let original = b"hello world".to_vec();
+        let mut data = original.clone();
+        apply_keystream(&mut data, &key);
+        assert_ne!(data, original);
+        apply_keystream(&mut data, &key);
+        assert_eq!(data, original);
+    }
+}
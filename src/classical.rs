@@ -0,0 +1,192 @@
+// src/classical.rs
+/*
+ * Classical (pre-modern) ciphers: Caesar, Atbash, Affine, Vigenere
+ */
+
+use crate::error::{Error, Result};
+
+/// A classical substitution or polyalphabetic cipher over the alphabet A-Z.
+pub trait Cipher {
+    fn encipher(&self, plaintext: &str) -> Result<String>;
+    fn decipher(&self, ciphertext: &str) -> Result<String>;
+}
+
+/// Normalizes input to uppercase A-Z, dropping anything else.
+fn normalize(input: &str) -> String {
+    input.chars().filter(|c| c.is_ascii_alphabetic()).map(|c| c.to_ascii_uppercase()).collect()
+}
+
+fn shift_char(c: u8, shift: i32) -> u8 {
+    let base = b'A';
+    let offset = (c - base) as i32;
+    let shifted = (offset + shift).rem_euclid(26);
+    base + shifted as u8
+}
+
+/// Caesar cipher: shifts each letter by a fixed amount.
+pub struct Caesar {
+    pub shift: i32,
+}
+
+impl Cipher for Caesar {
+    fn encipher(&self, plaintext: &str) -> Result<String> {
+        Ok(normalize(plaintext).bytes().map(|c| shift_char(c, self.shift) as char).collect())
+    }
+
+    fn decipher(&self, ciphertext: &str) -> Result<String> {
+        Ok(normalize(ciphertext).bytes().map(|c| shift_char(c, -self.shift) as char).collect())
+    }
+}
+
+/// Atbash cipher: reverses the alphabet (A<->Z, B<->Y, ...).
+pub struct Atbash;
+
+impl Cipher for Atbash {
+    fn encipher(&self, plaintext: &str) -> Result<String> {
+        Ok(normalize(plaintext).bytes().map(|c| b'Z' - (c - b'A')).map(|c| c as char).collect())
+    }
+
+    fn decipher(&self, ciphertext: &str) -> Result<String> {
+        // Atbash is its own inverse.
+        self.encipher(ciphertext)
+    }
+}
+
+/// Affine cipher: `C = (a*P + b) mod 26`. `a` must be coprime with 26.
+pub struct Affine {
+    pub a: i32,
+    pub b: i32,
+}
+
+impl Affine {
+    pub fn new(a: i32, b: i32) -> Result<Self> {
+        if gcd(a.rem_euclid(26), 26) != 1 {
+            return Err(Error::InvalidAffineKey(a));
+        }
+        Ok(Affine { a, b })
+    }
+}
+
+impl Cipher for Affine {
+    fn encipher(&self, plaintext: &str) -> Result<String> {
+        Ok(normalize(plaintext)
+            .bytes()
+            .map(|c| {
+                let p = (c - b'A') as i32;
+                let e = (self.a * p + self.b).rem_euclid(26);
+                (b'A' + e as u8) as char
+            })
+            .collect())
+    }
+
+    fn decipher(&self, ciphertext: &str) -> Result<String> {
+        let a_inv = mod_inverse(self.a.rem_euclid(26), 26).ok_or(Error::InvalidAffineKey(self.a))?;
+        Ok(normalize(ciphertext)
+            .bytes()
+            .map(|c| {
+                let e = (c - b'A') as i32;
+                let p = (a_inv * (e - self.b)).rem_euclid(26);
+                (b'A' + p as u8) as char
+            })
+            .collect())
+    }
+}
+
+/// Vigenere cipher: repeats an alphabetic key over the plaintext, skipping
+/// non-alphabetic characters when advancing the key index.
+pub struct Vigenere {
+    key: Vec<u8>,
+}
+
+impl Vigenere {
+    pub fn new(key: &str) -> Result<Self> {
+        let key: Vec<u8> = normalize(key).into_bytes();
+        if key.is_empty() {
+            return Err(Error::InvalidVigenereKey(key.len()));
+        }
+        Ok(Vigenere { key })
+    }
+}
+
+impl Cipher for Vigenere {
+    fn encipher(&self, plaintext: &str) -> Result<String> {
+        let mut j = 0;
+        Ok(normalize(plaintext)
+            .bytes()
+            .map(|p| {
+                let k = self.key[j % self.key.len()];
+                j += 1;
+                let c = ((p - b'A') as i32 + (k - b'A') as i32).rem_euclid(26);
+                (b'A' + c as u8) as char
+            })
+            .collect())
+    }
+
+    fn decipher(&self, ciphertext: &str) -> Result<String> {
+        let mut j = 0;
+        Ok(normalize(ciphertext)
+            .bytes()
+            .map(|c| {
+                let k = self.key[j % self.key.len()];
+                j += 1;
+                let p = ((c - b'A') as i32 - (k - b'A') as i32 + 26).rem_euclid(26);
+                (b'A' + p as u8) as char
+            })
+            .collect())
+    }
+}
+
+fn gcd(a: i32, b: i32) -> i32 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// Finds the modular inverse of `a` mod `m` via brute-force search, which is
+/// fine for the small modulus (26) used by the Affine cipher.
+fn mod_inverse(a: i32, m: i32) -> Option<i32> {
+    (1..m).find(|&x| (a * x).rem_euclid(m) == 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn caesar_round_trips() {
+        let cipher = Caesar { shift: 3 };
+        let ciphertext = cipher.encipher("Attack at dawn").unwrap();
+        assert_eq!(ciphertext, "DWWDFNDWGDZQ");
+        assert_eq!(cipher.decipher(&ciphertext).unwrap(), "ATTACKATDAWN");
+    }
+
+    #[test]
+    fn atbash_round_trips() {
+        let cipher = Atbash;
+        let ciphertext = cipher.encipher("Hello").unwrap();
+        assert_eq!(ciphertext, "SVOOL");
+        assert_eq!(cipher.decipher(&ciphertext).unwrap(), "HELLO");
+    }
+
+    #[test]
+    fn affine_rejects_non_coprime_key() {
+        assert!(Affine::new(2, 3).is_err());
+    }
+
+    #[test]
+    fn affine_round_trips() {
+        let cipher = Affine::new(5, 8).unwrap();
+        let ciphertext = cipher.encipher("AFFINE").unwrap();
+        assert_eq!(cipher.decipher(&ciphertext).unwrap(), "AFFINE");
+    }
+
+    #[test]
+    fn vigenere_round_trips_skipping_non_alpha() {
+        let cipher = Vigenere::new("LEMON").unwrap();
+        let ciphertext = cipher.encipher("Attack AT dawn").unwrap();
+        assert_eq!(ciphertext, "LXFOPVEFRNHR");
+        assert_eq!(cipher.decipher(&ciphertext).unwrap(), "ATTACKATDAWN");
+    }
+}
@@ -0,0 +1,175 @@
+// src/config.rs
+/*
+ * SSH-config-style profile parser for per-host cipher/key-exchange defaults
+ */
+
+use std::fs;
+use std::path::Path;
+
+use crate::error::Result;
+
+/// Resolved settings for a single host, after merging every matching
+/// `Host` block in file order (later blocks win per key).
+#[derive(Debug, Default, Clone)]
+pub struct HostSettings {
+    pub ciphers: Vec<String>,
+    pub identity_file: Option<String>,
+    pub certificate_file: Option<String>,
+    pub bind_address: Option<String>,
+}
+
+impl HostSettings {
+    fn merge_line(&mut self, key: &str, value: &str) {
+        match key.to_ascii_lowercase().as_str() {
+            "ciphers" => self.ciphers = value.split(',').map(|s| s.trim().to_string()).collect(),
+            "identityfile" => self.identity_file = Some(value.to_string()),
+            "certificatefile" => self.certificate_file = Some(value.to_string()),
+            "bindaddress" => self.bind_address = Some(value.to_string()),
+            _ => {}
+        }
+    }
+}
+
+struct HostBlock {
+    patterns: Vec<String>,
+    settings: HostSettings,
+}
+
+/// A parsed SSH-config-style file: an ordered list of `Host` blocks.
+pub struct Config {
+    blocks: Vec<HostBlock>,
+}
+
+impl Config {
+    /// Parses an SSH-config-style file: `Host` lines introduce a block of
+    /// one or more space-separated glob patterns, and subsequent indented
+    /// `Key Value` lines (until the next `Host` line) set that block's
+    /// settings. `#` starts a comment; blank lines are ignored.
+    pub fn parse(path: &Path) -> Result<Self> {
+        let text = fs::read_to_string(path)?;
+        let mut blocks = Vec::new();
+        let mut current: Option<HostBlock> = None;
+
+        for raw_line in text.lines() {
+            let line = strip_comment(raw_line).trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let (key, value) = match line.split_once(char::is_whitespace) {
+                Some((k, v)) => (k, v.trim()),
+                None => (line, ""),
+            };
+
+            if key.eq_ignore_ascii_case("host") {
+                if let Some(block) = current.take() {
+                    blocks.push(block);
+                }
+                current = Some(HostBlock {
+                    patterns: value.split_whitespace().map(|s| s.to_string()).collect(),
+                    settings: HostSettings::default(),
+                });
+            } else if let Some(block) = current.as_mut() {
+                block.settings.merge_line(key, value);
+            }
+        }
+
+        if let Some(block) = current.take() {
+            blocks.push(block);
+        }
+
+        Ok(Config { blocks })
+    }
+
+    /// Resolves the settings for `host` by merging every block whose
+    /// pattern list matches, in file order, so later blocks win per key.
+    pub fn query(&self, host: &str) -> HostSettings {
+        let mut resolved = HostSettings::default();
+        for block in &self.blocks {
+            if block.patterns.iter().any(|pattern| glob_match(pattern, host)) {
+                if !block.settings.ciphers.is_empty() {
+                    resolved.ciphers = block.settings.ciphers.clone();
+                }
+                if block.settings.identity_file.is_some() {
+                    resolved.identity_file = block.settings.identity_file.clone();
+                }
+                if block.settings.certificate_file.is_some() {
+                    resolved.certificate_file = block.settings.certificate_file.clone();
+                }
+                if block.settings.bind_address.is_some() {
+                    resolved.bind_address = block.settings.bind_address.clone();
+                }
+            }
+        }
+        resolved
+    }
+}
+
+fn strip_comment(line: &str) -> &str {
+    match line.find('#') {
+        Some(i) => &line[..i],
+        None => line,
+    }
+}
+
+/// Matches `host` against an SSH-config-style glob pattern where `*`
+/// matches any run of characters and `?` matches exactly one.
+fn glob_match(pattern: &str, host: &str) -> bool {
+    fn inner(pattern: &[u8], host: &[u8]) -> bool {
+        match (pattern.first(), host.first()) {
+            (None, None) => true,
+            (Some(b'*'), _) => inner(&pattern[1..], host) || (!host.is_empty() && inner(pattern, &host[1..])),
+            (Some(b'?'), Some(_)) => inner(&pattern[1..], &host[1..]),
+            (Some(p), Some(h)) if p == h => inner(&pattern[1..], &host[1..]),
+            _ => false,
+        }
+    }
+    inner(pattern.as_bytes(), host.as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    fn write_temp(contents: &str) -> std::path::PathBuf {
+        let id = COUNTER.fetch_add(1, Ordering::Relaxed);
+        let path = std::env::temp_dir().join(format!("cipherguard2_config_test_{id}.conf"));
+        let mut f = fs::File::create(&path).unwrap();
+        f.write_all(contents.as_bytes()).unwrap();
+        path
+    }
+
+    #[test]
+    fn glob_match_supports_star_and_question_mark() {
+        assert!(glob_match("*.example.com", "db.example.com"));
+        assert!(glob_match("db?", "db1"));
+        assert!(!glob_match("db?", "db12"));
+    }
+
+    #[test]
+    fn later_block_wins_on_conflicting_keys() {
+        let path = write_temp(
+            "Host *\n    Ciphers aes\n    BindAddress 0.0.0.0\n\nHost prod\n    Ciphers vigenere\n",
+        );
+        let config = Config::parse(&path).unwrap();
+        let _ = fs::remove_file(&path);
+
+        let settings = config.query("prod");
+        assert_eq!(settings.ciphers, vec!["vigenere".to_string()]);
+        assert_eq!(settings.bind_address.as_deref(), Some("0.0.0.0"));
+    }
+
+    #[test]
+    fn non_matching_host_gets_no_settings() {
+        let path = write_temp("Host prod\n    Ciphers vigenere\n");
+        let config = Config::parse(&path).unwrap();
+        let _ = fs::remove_file(&path);
+
+        let settings = config.query("staging");
+        assert!(settings.ciphers.is_empty());
+    }
+}
@@ -0,0 +1,79 @@
+// src/error.rs
+/*
+ * Shared error type for CipherGuard2
+ */
+
+use std::fmt;
+
+/// Crate-wide result alias.
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Errors that can surface from any CipherGuard2 operation.
+#[derive(Debug)]
+pub enum Error {
+    /// Wraps a filesystem I/O failure.
+    Io(std::io::Error),
+    /// A hex-encoded key string was malformed (odd length or non-hex digits).
+    InvalidHexKey(String),
+    /// Attempted to add an entry whose name already exists in the vault.
+    DuplicateEntry(String),
+    /// Attempted to look up or remove an entry that does not exist.
+    EntryNotFound(String),
+    /// The vault could not be decrypted, e.g. due to a wrong master password.
+    VaultDecryptFailed,
+    /// The vault could not be serialized.
+    Serialize(String),
+    /// A `--password` flag was required but not supplied at all.
+    MissingPassword,
+    /// An Affine cipher key `a` was not coprime with 26.
+    InvalidAffineKey(i32),
+    /// A Vigenere cipher key was empty after normalization.
+    InvalidVigenereKey(usize),
+    /// A `--key` value for `classic --algo caesar` was not a valid integer shift.
+    InvalidCaesarKey(String),
+    /// A `--key` value for `classic --algo affine` was not in "a,b" form.
+    InvalidAffineKeyFormat(String),
+    /// A vault operation was attempted with an empty master password.
+    EmptyMasterPassword,
+    /// A resolved `Ciphers` host preference did not name a known cipher.
+    UnknownCipherPreference(String),
+    /// A classical cipher was selected for a file that is not valid UTF-8.
+    NonUtf8CipherInput(std::path::PathBuf),
+    /// A `gen --length` was smaller than the number of enabled character
+    /// classes, so no password could cover every class.
+    PasswordTooShortForClasses { length: usize, classes: usize },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "I/O error: {e}"),
+            Error::InvalidHexKey(key) => write!(f, "invalid hex key: {key}"),
+            Error::DuplicateEntry(name) => write!(f, "entry already exists: {name}"),
+            Error::EntryNotFound(name) => write!(f, "no such entry: {name}"),
+            Error::VaultDecryptFailed => write!(f, "failed to decrypt vault (wrong master password?)"),
+            Error::Serialize(msg) => write!(f, "failed to serialize vault: {msg}"),
+            Error::MissingPassword => write!(f, "no password provided; use --password or --password <secret>"),
+            Error::InvalidAffineKey(a) => write!(f, "affine key 'a' ({a}) is not coprime with 26"),
+            Error::InvalidVigenereKey(len) => write!(f, "vigenere key has no alphabetic characters (len {len})"),
+            Error::InvalidCaesarKey(key) => write!(f, "invalid caesar shift: {key}"),
+            Error::InvalidAffineKeyFormat(key) => write!(f, "invalid affine key, expected \"a,b\": {key}"),
+            Error::EmptyMasterPassword => write!(f, "master password must not be empty"),
+            Error::UnknownCipherPreference(name) => write!(f, "unknown cipher preference \"{name}\""),
+            Error::NonUtf8CipherInput(path) => {
+                write!(f, "{} is not valid UTF-8 text, required by the selected classical cipher", path.display())
+            }
+            Error::PasswordTooShortForClasses { length, classes } => {
+                write!(f, "length {length} is too short to cover {classes} enabled character classes")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::Io(e)
+    }
+}
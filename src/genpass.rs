@@ -0,0 +1,151 @@
+// src/genpass.rs
+/*
+ * Cryptographically random password generation
+ */
+
+use rand::rngs::OsRng;
+use rand::RngCore;
+
+use crate::error::{Error, Result};
+
+const LOWER: &[u8] = b"abcdefghijklmnopqrstuvwxyz";
+const UPPER: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+const DIGITS: &[u8] = b"0123456789";
+const SYMBOLS: &[u8] = b"!@#$%^&*()-_=+[]{}";
+
+/// Which character classes a generated password must draw from.
+pub struct Charset {
+    pub digits: bool,
+    pub symbols: bool,
+}
+
+impl Charset {
+    fn classes(&self) -> Vec<&'static [u8]> {
+        let mut classes = vec![LOWER, UPPER];
+        if self.digits {
+            classes.push(DIGITS);
+        }
+        if self.symbols {
+            classes.push(SYMBOLS);
+        }
+        classes
+    }
+}
+
+/// Generates a single password of `length` characters drawn from the
+/// enabled classes in `charset`, guaranteeing at least one character from
+/// each enabled class.
+///
+/// Returns [`Error::PasswordTooShortForClasses`] if `length` is smaller than
+/// the number of enabled classes, since no password of that length could
+/// cover them all.
+pub fn generate(length: usize, charset: &Charset) -> Result<String> {
+    if length == 0 {
+        return Ok(String::new());
+    }
+
+    let classes = charset.classes();
+    if length < classes.len() {
+        return Err(Error::PasswordTooShortForClasses { length, classes: classes.len() });
+    }
+
+    let alphabet: Vec<u8> = classes.iter().flat_map(|c| c.iter().copied()).collect();
+    let mut rng = OsRng;
+
+    loop {
+        let mut password: Vec<u8> = (0..length).map(|_| random_byte(&mut rng, &alphabet)).collect();
+
+        // Patch in any missing class, tracking which slots were already
+        // patched this round so two missing classes can't land on (and
+        // erase) each other's slot.
+        let mut patched_slots: Vec<usize> = Vec::new();
+        for class in &classes {
+            if !password.iter().any(|b| class.contains(b)) {
+                let slot = pick_unpatched_slot(&mut rng, password.len(), &patched_slots);
+                password[slot] = random_byte(&mut rng, class);
+                patched_slots.push(slot);
+            }
+        }
+
+        // A patch for one class can still land on the sole, unpatched
+        // occurrence of a different class and erase it. Re-verify full
+        // coverage and retry the whole draw rather than accept a password
+        // that silently dropped a class.
+        if classes.iter().all(|class| password.iter().any(|b| class.contains(b))) {
+            return Ok(String::from_utf8(password).expect("password alphabet is ASCII"));
+        }
+    }
+}
+
+/// Picks a slot index in `0..len` not already present in `patched`. Falls
+/// back to the last slot if every slot has already been patched (only
+/// possible when `length` is smaller than the number of enabled classes).
+fn pick_unpatched_slot(rng: &mut OsRng, len: usize, patched: &[usize]) -> usize {
+    if patched.len() >= len {
+        return len - 1;
+    }
+    loop {
+        let candidate = (random_u32(rng) as usize) % len;
+        if !patched.contains(&candidate) {
+            return candidate;
+        }
+    }
+}
+
+fn random_u32(rng: &mut OsRng) -> u32 {
+    rng.next_u32()
+}
+
+fn random_byte(rng: &mut OsRng, alphabet: &[u8]) -> u8 {
+    alphabet[(random_u32(rng) as usize) % alphabet.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generated_password_has_requested_length() {
+        let charset = Charset { digits: true, symbols: true };
+        let password = generate(16, &charset).unwrap();
+        assert_eq!(password.len(), 16);
+    }
+
+    #[test]
+    fn generated_password_covers_enabled_classes() {
+        let charset = Charset { digits: true, symbols: true };
+        for _ in 0..50 {
+            let password = generate(8, &charset).unwrap();
+            assert!(password.bytes().any(|b| DIGITS.contains(&b)));
+            assert!(password.bytes().any(|b| SYMBOLS.contains(&b)));
+        }
+    }
+
+    #[test]
+    fn patching_two_missing_classes_never_clobbers_either() {
+        let charset = Charset { digits: true, symbols: true };
+        for _ in 0..500 {
+            let password = generate(8, &charset).unwrap();
+            assert!(password.bytes().any(|b| DIGITS.contains(&b)));
+            assert!(password.bytes().any(|b| SYMBOLS.contains(&b)));
+        }
+    }
+
+    #[test]
+    fn generate_errors_instead_of_looping_when_length_is_too_short() {
+        let charset = Charset { digits: true, symbols: true };
+        assert!(generate(1, &charset).is_err());
+        assert!(generate(3, &charset).is_err());
+        assert!(generate(4, &charset).is_ok());
+    }
+
+    #[test]
+    fn disabled_classes_are_never_used() {
+        let charset = Charset { digits: false, symbols: false };
+        for _ in 0..50 {
+            let password = generate(12, &charset).unwrap();
+            assert!(!password.bytes().any(|b| DIGITS.contains(&b)));
+            assert!(!password.bytes().any(|b| SYMBOLS.contains(&b)));
+        }
+    }
+}
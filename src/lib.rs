@@ -0,0 +1,545 @@
+// src/lib.rs
+/*
+ * Library entry point for CipherGuard2
+ */
+
+pub mod bitwarden;
+pub mod cipher;
+pub mod classical;
+pub mod config;
+pub mod error;
+pub mod genpass;
+pub mod vault;
+
+use std::fs;
+use std::io::{self, Write};
+use std::path::PathBuf;
+
+use clap::{Parser, Subcommand};
+
+pub use error::{Error, Result};
+use vault::{Entry, Vault};
+
+const DEFAULT_VAULT_PATH: &str = "vault.db";
+
+#[derive(Parser)]
+#[command(version, about = "CipherGuard2 - A Rust implementation")]
+pub struct Cli {
+    /// Enable verbose output
+    #[arg(short, long, global = true)]
+    pub verbose: bool,
+
+    /// Path to an SSH-config-style file of per-host cipher preferences
+    #[arg(long, global = true)]
+    pub config: Option<PathBuf>,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Encrypt a file in place (or to --out) using a hex-encoded key
+    Encrypt {
+        /// Path to the file to encrypt
+        #[arg(long)]
+        file: PathBuf,
+        /// Hex-encoded stream cipher key
+        #[arg(long)]
+        key: String,
+        /// Where to write the result (defaults to overwriting --file)
+        #[arg(long)]
+        out: Option<PathBuf>,
+        /// Named host profile to resolve cipher preferences from --config
+        #[arg(long)]
+        host: Option<String>,
+    },
+    /// Decrypt a file in place (or to --out) using a hex-encoded key
+    Decrypt {
+        /// Path to the file to decrypt
+        #[arg(long)]
+        file: PathBuf,
+        /// Hex-encoded stream cipher key
+        #[arg(long)]
+        key: String,
+        /// Where to write the result (defaults to overwriting --file)
+        #[arg(long)]
+        out: Option<PathBuf>,
+        /// Named host profile to resolve cipher preferences from --config
+        #[arg(long)]
+        host: Option<String>,
+    },
+    /// Add an entry to the vault
+    Add {
+        /// Entry name
+        name: String,
+        /// Account username
+        #[arg(long)]
+        username: String,
+        /// Account password; pass with no value to be prompted interactively
+        #[arg(long, num_args = 0..=1)]
+        password: Option<Option<String>>,
+        /// Freeform notes
+        #[arg(long)]
+        notes: Option<String>,
+        /// Path to the vault file
+        #[arg(long, default_value = DEFAULT_VAULT_PATH)]
+        vault: PathBuf,
+    },
+    /// List entries in the vault
+    List {
+        /// Path to the vault file
+        #[arg(long, default_value = DEFAULT_VAULT_PATH)]
+        vault: PathBuf,
+    },
+    /// Remove an entry from the vault
+    Remove {
+        /// Entry name
+        name: String,
+        /// Path to the vault file
+        #[arg(long, default_value = DEFAULT_VAULT_PATH)]
+        vault: PathBuf,
+    },
+    /// Edit an existing vault entry
+    Edit {
+        /// Entry name
+        name: String,
+        /// New username
+        #[arg(long)]
+        username: Option<String>,
+        /// New password; pass with no value to be prompted interactively
+        #[arg(long, num_args = 0..=1)]
+        password: Option<Option<String>>,
+        /// New notes
+        #[arg(long)]
+        notes: Option<String>,
+        /// Path to the vault file
+        #[arg(long, default_value = DEFAULT_VAULT_PATH)]
+        vault: PathBuf,
+    },
+    /// Import entries from a Bitwarden unencrypted JSON export
+    Import {
+        /// Path to the Bitwarden JSON export
+        #[arg(long)]
+        file: PathBuf,
+        /// Path to the vault file
+        #[arg(long, default_value = DEFAULT_VAULT_PATH)]
+        vault: PathBuf,
+    },
+    /// Export the vault to Bitwarden's unencrypted JSON format
+    Export {
+        /// Path to write the Bitwarden JSON export to
+        #[arg(long)]
+        file: PathBuf,
+        /// Path to the vault file
+        #[arg(long, default_value = DEFAULT_VAULT_PATH)]
+        vault: PathBuf,
+    },
+    /// Generate one or more cryptographically random passwords
+    Gen {
+        /// Password length
+        #[arg(long, default_value_t = 20)]
+        length: usize,
+        /// Exclude symbol characters
+        #[arg(long)]
+        no_symbols: bool,
+        /// Exclude digit characters
+        #[arg(long)]
+        no_digits: bool,
+        /// Number of passwords to generate
+        #[arg(long, default_value_t = 1)]
+        count: usize,
+    },
+    /// Encipher or decipher text with a classical cipher
+    Classic {
+        /// Which classical cipher to use
+        #[arg(long)]
+        algo: ClassicalAlgo,
+        /// Cipher key (format depends on --algo: shift for caesar, "a,b" for
+        /// affine, a keyword for vigenere, ignored for atbash)
+        #[arg(long, default_value = "")]
+        key: String,
+        /// Text to transform
+        #[arg(long)]
+        text: String,
+        /// Decipher instead of encipher
+        #[arg(long)]
+        decipher: bool,
+    },
+}
+
+/// The classical cipher algorithms selectable via `classic --algo`.
+#[derive(Clone, clap::ValueEnum)]
+pub enum ClassicalAlgo {
+    Caesar,
+    Atbash,
+    Affine,
+    Vigenere,
+}
+
+/// Name used for the default file cipher in `Ciphers` host preferences; any
+/// other name must resolve to a [`ClassicalAlgo`].
+const STREAM_CIPHER_NAME: &str = "stream";
+
+/// Builds the classical cipher named by a resolved `Ciphers` preference, or
+/// by `classic --algo`.
+fn build_classical_cipher(algo: ClassicalAlgo, key: &str) -> Result<Box<dyn classical::Cipher>> {
+    match algo {
+        ClassicalAlgo::Caesar => {
+            let shift: i32 = key.trim().parse().map_err(|_| Error::InvalidCaesarKey(key.to_string()))?;
+            Ok(Box::new(classical::Caesar { shift }))
+        }
+        ClassicalAlgo::Atbash => Ok(Box::new(classical::Atbash)),
+        ClassicalAlgo::Affine => {
+            let (a, b) = key
+                .split_once(',')
+                .and_then(|(a, b)| Some((a.trim().parse().ok()?, b.trim().parse().ok()?)))
+                .ok_or_else(|| Error::InvalidAffineKeyFormat(key.to_string()))?;
+            Ok(Box::new(classical::Affine::new(a, b)?))
+        }
+        ClassicalAlgo::Vigenere => Ok(Box::new(classical::Vigenere::new(key)?)),
+    }
+}
+
+/// Dispatches the parsed CLI arguments to the appropriate subcommand.
+pub fn run(cli: Cli) -> Result<()> {
+    match cli.command {
+        Some(Command::Encrypt { file, key, out, host }) => apply_cipher(
+            &file,
+            &key,
+            out.as_deref(),
+            cli.verbose,
+            resolve_host_cipher(cli.config.as_deref(), host.as_deref())?,
+            false,
+        ),
+        Some(Command::Decrypt { file, key, out, host }) => apply_cipher(
+            &file,
+            &key,
+            out.as_deref(),
+            cli.verbose,
+            resolve_host_cipher(cli.config.as_deref(), host.as_deref())?,
+            true,
+        ),
+        Some(Command::Add { name, username, password, notes, vault }) => {
+            cmd_add(&vault, name, username, password, notes)
+        }
+        Some(Command::List { vault }) => cmd_list(&vault),
+        Some(Command::Remove { name, vault }) => cmd_remove(&vault, &name),
+        Some(Command::Edit { name, username, password, notes, vault }) => {
+            cmd_edit(&vault, &name, username, password, notes)
+        }
+        Some(Command::Import { file, vault }) => cmd_import(&file, &vault),
+        Some(Command::Export { file, vault }) => cmd_export(&file, &vault),
+        Some(Command::Gen { length, no_symbols, no_digits, count }) => cmd_gen(length, !no_symbols, !no_digits, count),
+        Some(Command::Classic { algo, key, text, decipher }) => cmd_classic(algo, &key, &text, decipher),
+        None => {
+            if cli.verbose {
+                println!("CipherGuard2: no subcommand given, nothing to do");
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Encrypts or decrypts `file`, selecting the cipher named by
+/// `preferred_cipher` (resolved from `--host` against `--config`) when
+/// present, and falling back to the hex-keyed stream cipher otherwise.
+fn apply_cipher(
+    file: &std::path::Path,
+    key: &str,
+    out: Option<&std::path::Path>,
+    verbose: bool,
+    preferred_cipher: Option<String>,
+    decipher: bool,
+) -> Result<()> {
+    let cipher_name = preferred_cipher.as_deref().unwrap_or(STREAM_CIPHER_NAME);
+    let dest = out.unwrap_or(file);
+
+    let data = if cipher_name.eq_ignore_ascii_case(STREAM_CIPHER_NAME) {
+        let key_bytes = cipher::decode_hex_key(key)?;
+        let mut data = fs::read(file)?;
+        cipher::apply_keystream(&mut data, &key_bytes);
+        data
+    } else {
+        let algo = <ClassicalAlgo as clap::ValueEnum>::from_str(cipher_name, true)
+            .map_err(|_| Error::UnknownCipherPreference(cipher_name.to_string()))?;
+        let cipher = build_classical_cipher(algo, key)?;
+        let text = fs::read_to_string(file).map_err(|_| Error::NonUtf8CipherInput(file.to_path_buf()))?;
+        let output = if decipher { cipher.decipher(&text)? } else { cipher.encipher(&text)? };
+        output.into_bytes()
+    };
+
+    fs::write(dest, &data)?;
+
+    if verbose {
+        println!("used cipher \"{cipher_name}\"");
+        println!("wrote {} bytes to {}", data.len(), dest.display());
+    }
+
+    Ok(())
+}
+
+/// Resolves the preferred cipher name for `host` from `config_path`, if both
+/// are given. Returns `None` when there is no config, no host, or no
+/// `Ciphers` preference configured for the matched host.
+fn resolve_host_cipher(config_path: Option<&std::path::Path>, host: Option<&str>) -> Result<Option<String>> {
+    let (config_path, host) = match (config_path, host) {
+        (Some(c), Some(h)) => (c, h),
+        _ => return Ok(None),
+    };
+
+    let config = config::Config::parse(config_path)?;
+    let settings = config.query(host);
+    Ok(settings.ciphers.into_iter().next())
+}
+
+fn cmd_add(
+    vault_path: &std::path::Path,
+    name: String,
+    username: String,
+    password: Option<Option<String>>,
+    notes: Option<String>,
+) -> Result<()> {
+    let password = resolve_password(password, "entry password")?;
+    let master = prompt_line("Master password: ")?;
+
+    let mut v = if vault_path.exists() {
+        Vault::load(vault_path, &master)?
+    } else {
+        Vault::new()
+    };
+
+    v.add(Entry {
+        name,
+        username,
+        password,
+        notes: notes.unwrap_or_default(),
+    })?;
+    v.save(vault_path, &master)
+}
+
+fn cmd_list(vault_path: &std::path::Path) -> Result<()> {
+    let master = prompt_line("Master password: ")?;
+    let v = Vault::load(vault_path, &master)?;
+    for entry in &v.entries {
+        println!("{}\t{}", entry.name, entry.username);
+    }
+    Ok(())
+}
+
+fn cmd_remove(vault_path: &std::path::Path, name: &str) -> Result<()> {
+    let master = prompt_line("Master password: ")?;
+    let mut v = Vault::load(vault_path, &master)?;
+    v.remove(name)?;
+    v.save(vault_path, &master)
+}
+
+fn cmd_edit(
+    vault_path: &std::path::Path,
+    name: &str,
+    username: Option<String>,
+    password: Option<Option<String>>,
+    notes: Option<String>,
+) -> Result<()> {
+    let master = prompt_line("Master password: ")?;
+    let mut v = Vault::load(vault_path, &master)?;
+
+    let password = match password {
+        Some(p) => Some(resolve_password(Some(p), "new entry password")?),
+        None => None,
+    };
+
+    let entry = v.find_mut(name)?;
+    if let Some(username) = username {
+        entry.username = username;
+    }
+    if let Some(password) = password {
+        entry.password = password;
+    }
+    if let Some(notes) = notes {
+        entry.notes = notes;
+    }
+
+    v.save(vault_path, &master)
+}
+
+fn cmd_gen(length: usize, symbols: bool, digits: bool, count: usize) -> Result<()> {
+    let charset = genpass::Charset { digits, symbols };
+    let mut out = String::new();
+    for _ in 0..count {
+        out.push_str(&genpass::generate(length, &charset)?);
+        out.push('\n');
+    }
+    print!("{out}");
+    Ok(())
+}
+
+fn cmd_classic(algo: ClassicalAlgo, key: &str, text: &str, decipher: bool) -> Result<()> {
+    let cipher = build_classical_cipher(algo, key)?;
+    let output = if decipher { cipher.decipher(text)? } else { cipher.encipher(text)? };
+    println!("{output}");
+    Ok(())
+}
+
+fn cmd_import(file: &std::path::Path, vault_path: &std::path::Path) -> Result<()> {
+    let master = prompt_line("Master password: ")?;
+    cmd_import_with_password(file, vault_path, &master)
+}
+
+/// Does the work of [`cmd_import`] given an already-resolved master
+/// password, so the merge behavior can be tested without prompting stdin.
+fn cmd_import_with_password(file: &std::path::Path, vault_path: &std::path::Path, master: &str) -> Result<()> {
+    let raw = fs::read(file)?;
+    let export: bitwarden::BitwardenExport = serde_json::from_slice(&raw).map_err(|e| Error::Serialize(e.to_string()))?;
+    let (imported, warnings) = bitwarden::from_bitwarden(export);
+
+    for warning in &warnings {
+        eprintln!("warning: {warning}");
+    }
+
+    let mut v = if vault_path.exists() {
+        Vault::load(vault_path, master)?
+    } else {
+        Vault::new()
+    };
+
+    for entry in imported.entries {
+        let name = entry.name.clone();
+        if let Err(e) = v.add(entry) {
+            eprintln!("warning: skipping \"{name}\": {e}");
+        }
+    }
+
+    v.save(vault_path, master)
+}
+
+fn cmd_export(file: &std::path::Path, vault_path: &std::path::Path) -> Result<()> {
+    let master = prompt_line("Master password: ")?;
+    let v = Vault::load(vault_path, &master)?;
+    let export = bitwarden::to_bitwarden(&v);
+    let json = serde_json::to_vec_pretty(&export).map_err(|e| Error::Serialize(e.to_string()))?;
+    fs::write(file, json)?;
+    Ok(())
+}
+
+/// Resolves the `Option<Option<String>>` produced by a `--password` flag that
+/// may be absent, present with a value, or present with no value (prompt).
+fn resolve_password(password: Option<Option<String>>, prompt_label: &str) -> Result<String> {
+    match password {
+        Some(Some(value)) => Ok(value),
+        Some(None) => prompt_line(&format!("{prompt_label}: ")),
+        None => Err(Error::MissingPassword),
+    }
+}
+
+fn prompt_line(label: &str) -> Result<String> {
+    print!("{label}");
+    io::stdout().flush()?;
+    let mut line = String::new();
+    io::stdin().read_line(&mut line)?;
+    Ok(line.trim_end_matches(['\r', '\n']).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    fn write_temp(name_prefix: &str, contents: &[u8]) -> std::path::PathBuf {
+        let id = COUNTER.fetch_add(1, Ordering::Relaxed);
+        let path = std::env::temp_dir().join(format!("cipherguard2_lib_test_{name_prefix}_{id}"));
+        fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn host_cipher_preference_selects_a_classical_cipher_for_encrypt() {
+        let config_path = write_temp(
+            "config",
+            b"Host secretbox\n    Ciphers vigenere\n",
+        );
+        let file_path = write_temp("plaintext", b"attack at dawn");
+
+        apply_cipher(
+            &file_path,
+            "lemon",
+            None,
+            false,
+            resolve_host_cipher(Some(&config_path), Some("secretbox")).unwrap(),
+            false,
+        )
+        .unwrap();
+        let encrypted = fs::read_to_string(&file_path).unwrap();
+        assert_eq!(encrypted, "LXFOPVEFRNHR");
+
+        apply_cipher(
+            &file_path,
+            "lemon",
+            None,
+            false,
+            resolve_host_cipher(Some(&config_path), Some("secretbox")).unwrap(),
+            true,
+        )
+        .unwrap();
+        let decrypted = fs::read_to_string(&file_path).unwrap();
+        assert_eq!(decrypted, "ATTACKATDAWN");
+
+        let _ = fs::remove_file(&config_path);
+        let _ = fs::remove_file(&file_path);
+    }
+
+    #[test]
+    fn import_merges_into_an_existing_vault_instead_of_overwriting_it() {
+        let vault_path = write_temp("vault", b"");
+        let mut v = Vault::new();
+        v.add(Entry {
+            name: "existing".to_string(),
+            username: "alice".to_string(),
+            password: "hunter2".to_string(),
+            notes: String::new(),
+        })
+        .unwrap();
+        v.save(&vault_path, "correct horse").unwrap();
+
+        let export = bitwarden::BitwardenExport {
+            folders: Vec::new(),
+            items: vec![bitwarden::BitwardenItem {
+                item_type: 1,
+                name: "imported".to_string(),
+                notes: String::new(),
+                login: Some(bitwarden::BitwardenLogin {
+                    username: Some("bob".to_string()),
+                    password: Some("swordfish".to_string()),
+                    uris: Vec::new(),
+                }),
+            }],
+        };
+        let import_path = write_temp("import", &serde_json::to_vec(&export).unwrap());
+
+        cmd_import_with_password(&import_path, &vault_path, "correct horse").unwrap();
+
+        let merged = Vault::load(&vault_path, "correct horse").unwrap();
+        let names: Vec<&str> = merged.entries.iter().map(|e| e.name.as_str()).collect();
+        assert!(names.contains(&"existing"));
+        assert!(names.contains(&"imported"));
+
+        let _ = fs::remove_file(&vault_path);
+        let _ = fs::remove_file(&import_path);
+    }
+
+    #[test]
+    fn no_host_preference_falls_back_to_stream_cipher() {
+        let file_path = write_temp("bytes", b"hello world");
+        apply_cipher(&file_path, "2a13", None, false, None, false).unwrap();
+        let ciphertext = fs::read(&file_path).unwrap();
+        assert_ne!(ciphertext, b"hello world");
+
+        apply_cipher(&file_path, "2a13", None, false, None, true).unwrap();
+        let plaintext = fs::read(&file_path).unwrap();
+        assert_eq!(plaintext, b"hello world");
+
+        let _ = fs::remove_file(&file_path);
+    }
+}
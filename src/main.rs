@@ -4,17 +4,9 @@
  */
 
 use clap::Parser;
-use cipherguard2::{Result, run};
-
-#[derive(Parser)]
-#[command(version, about = "CipherGuard2 - A Rust implementation")]
-struct Cli {
-    /// Enable verbose output
-    #[arg(short, long)]
-    verbose: bool,
-}
+use cipherguard2::{Cli, Result, run};
 
 fn main() -> Result<()> {
-    let args = Cli::parse();
-    run(args.verbose)
+    let cli = Cli::parse();
+    run(cli)
 }
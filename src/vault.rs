@@ -0,0 +1,233 @@
+// src/vault.rs
+/*
+ * Encrypted password-vault subsystem
+ */
+
+use std::fs;
+use std::path::Path;
+
+use rand::rngs::OsRng;
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::cipher;
+use crate::error::{Error, Result};
+
+/// Length in bytes of the random per-save nonce stored ahead of the
+/// ciphertext on disk.
+const NONCE_LEN: usize = 16;
+
+/// A single credential stored in the vault.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Entry {
+    pub name: String,
+    pub username: String,
+    pub password: String,
+    #[serde(default)]
+    pub notes: String,
+}
+
+/// An in-memory collection of vault entries.
+///
+/// Serialized as JSON and encrypted at rest under a keystream expanded via
+/// [`derive_keystream`] from the master password and a random per-save
+/// nonce (stored as the first [`NONCE_LEN`] bytes of the file), so that two
+/// saves under the same master password never reuse the same keystream.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Vault {
+    pub entries: Vec<Entry>,
+}
+
+impl Vault {
+    /// Creates an empty vault.
+    pub fn new() -> Self {
+        Vault { entries: Vec::new() }
+    }
+
+    /// Adds an entry, returning an error if the name is already taken.
+    pub fn add(&mut self, entry: Entry) -> Result<()> {
+        if self.entries.iter().any(|e| e.name == entry.name) {
+            return Err(Error::DuplicateEntry(entry.name));
+        }
+        self.entries.push(entry);
+        Ok(())
+    }
+
+    /// Removes the entry with the given name, returning an error if absent.
+    pub fn remove(&mut self, name: &str) -> Result<()> {
+        let len_before = self.entries.len();
+        self.entries.retain(|e| e.name != name);
+        if self.entries.len() == len_before {
+            return Err(Error::EntryNotFound(name.to_string()));
+        }
+        Ok(())
+    }
+
+    /// Returns a mutable reference to the entry with the given name.
+    pub fn find_mut(&mut self, name: &str) -> Result<&mut Entry> {
+        self.entries
+            .iter_mut()
+            .find(|e| e.name == name)
+            .ok_or_else(|| Error::EntryNotFound(name.to_string()))
+    }
+
+    /// Loads and decrypts a vault from `path` under `master_password`.
+    pub fn load(path: &Path, master_password: &str) -> Result<Self> {
+        reject_empty_master_password(master_password)?;
+        let data = fs::read(path)?;
+        if data.len() < NONCE_LEN {
+            return Err(Error::VaultDecryptFailed);
+        }
+        let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+        let mut plaintext = ciphertext.to_vec();
+        let keystream = derive_keystream(master_password, nonce, plaintext.len());
+        cipher::apply_keystream(&mut plaintext, &keystream);
+        let vault = serde_json::from_slice(&plaintext).map_err(|_| Error::VaultDecryptFailed)?;
+        Ok(vault)
+    }
+
+    /// Serializes and encrypts the vault to `path` under `master_password`.
+    pub fn save(&self, path: &Path, master_password: &str) -> Result<()> {
+        reject_empty_master_password(master_password)?;
+        let mut plaintext = serde_json::to_vec(self).map_err(|e| Error::Serialize(e.to_string()))?;
+
+        let mut nonce = [0u8; NONCE_LEN];
+        OsRng.fill_bytes(&mut nonce);
+
+        let keystream = derive_keystream(master_password, &nonce, plaintext.len());
+        cipher::apply_keystream(&mut plaintext, &keystream);
+
+        let mut out = Vec::with_capacity(NONCE_LEN + plaintext.len());
+        out.extend_from_slice(&nonce);
+        out.extend_from_slice(&plaintext);
+        fs::write(path, out)?;
+        Ok(())
+    }
+}
+
+fn reject_empty_master_password(master_password: &str) -> Result<()> {
+    if master_password.is_empty() {
+        return Err(Error::EmptyMasterPassword);
+    }
+    Ok(())
+}
+
+/// Expands a master password and a per-save nonce into a `length`-byte
+/// keystream using SHA-256 in counter mode
+/// (`SHA256(password || nonce || counter)` blocks concatenated).
+///
+/// The nonce is what keeps this from being a two-time pad: without it, any
+/// two saves under the same master password (e.g. after an edit) would
+/// derive the identical keystream from identical input, and XORing the two
+/// ciphertexts together would cancel the keystream and leak the XOR of the
+/// two plaintexts. A fresh random nonce per save means no two saves ever
+/// derive the same keystream, even under the same password.
+fn derive_keystream(master_password: &str, nonce: &[u8], length: usize) -> Vec<u8> {
+    let mut keystream = Vec::with_capacity(length);
+    let mut counter: u64 = 0;
+    while keystream.len() < length {
+        let mut hasher = Sha256::new();
+        hasher.update(master_password.as_bytes());
+        hasher.update(nonce);
+        hasher.update(counter.to_le_bytes());
+        keystream.extend_from_slice(&hasher.finalize());
+        counter += 1;
+    }
+    keystream.truncate(length);
+    keystream
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_entry(name: &str) -> Entry {
+        Entry {
+            name: name.to_string(),
+            username: "alice".to_string(),
+            password: "hunter2".to_string(),
+            notes: String::new(),
+        }
+    }
+
+    #[test]
+    fn add_and_remove_round_trip() {
+        let mut vault = Vault::new();
+        vault.add(sample_entry("github")).unwrap();
+        assert_eq!(vault.entries.len(), 1);
+        vault.remove("github").unwrap();
+        assert!(vault.entries.is_empty());
+    }
+
+    #[test]
+    fn add_rejects_duplicate_name() {
+        let mut vault = Vault::new();
+        vault.add(sample_entry("github")).unwrap();
+        assert!(vault.add(sample_entry("github")).is_err());
+    }
+
+    #[test]
+    fn remove_missing_entry_errors() {
+        let mut vault = Vault::new();
+        assert!(vault.remove("nope").is_err());
+    }
+
+    #[test]
+    fn save_and_load_round_trip() {
+        let mut vault = Vault::new();
+        vault.add(sample_entry("github")).unwrap();
+
+        let path = std::env::temp_dir().join("cipherguard2_vault_test.bin");
+        vault.save(&path, "correct horse").unwrap();
+        let loaded = Vault::load(&path, "correct horse").unwrap();
+        let _ = std::fs::remove_file(&path);
+
+        assert_eq!(loaded.entries.len(), 1);
+        assert_eq!(loaded.entries[0].name, "github");
+    }
+
+    #[test]
+    fn save_rejects_empty_master_password() {
+        let vault = Vault::new();
+        let path = std::env::temp_dir().join("cipherguard2_vault_test_empty_password.bin");
+        assert!(vault.save(&path, "").is_err());
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn derive_keystream_does_not_repeat_within_requested_length() {
+        let keystream = derive_keystream("correct horse", b"fixed-nonce", 64);
+        assert_eq!(keystream.len(), 64);
+        assert_ne!(keystream[..32], keystream[32..]);
+    }
+
+    #[test]
+    fn derive_keystream_differs_across_nonces_for_same_password() {
+        let a = derive_keystream("correct horse", b"nonce-a", 32);
+        let b = derive_keystream("correct horse", b"nonce-b", 32);
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn two_saves_under_the_same_password_do_not_reuse_a_keystream() {
+        let mut vault_a = Vault::new();
+        vault_a.add(sample_entry("github")).unwrap();
+        let mut vault_b = Vault::new();
+        vault_b.add(sample_entry("gitlab")).unwrap();
+
+        let path_a = std::env::temp_dir().join("cipherguard2_vault_test_nonce_a.bin");
+        let path_b = std::env::temp_dir().join("cipherguard2_vault_test_nonce_b.bin");
+        vault_a.save(&path_a, "correct horse").unwrap();
+        vault_b.save(&path_b, "correct horse").unwrap();
+
+        let bytes_a = fs::read(&path_a).unwrap();
+        let bytes_b = fs::read(&path_b).unwrap();
+        let _ = fs::remove_file(&path_a);
+        let _ = fs::remove_file(&path_b);
+
+        // Different random nonces per save, so the stored prefixes differ
+        // even though both vaults were saved under the same master password.
+        assert_ne!(bytes_a[..NONCE_LEN], bytes_b[..NONCE_LEN]);
+    }
+}